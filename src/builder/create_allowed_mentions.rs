@@ -0,0 +1,229 @@
+use serde_json::{Map, Value};
+use std::default::Default;
+
+/// A builder to manage the allowed mentions on a message, used when
+/// executing a webhook.
+///
+/// Refer to the documentation for [`ExecuteWebhook::allowed_mentions`] for
+/// more information.
+///
+/// # Examples
+///
+/// Suppressing every ping except an explicit mention of one user:
+///
+/// ```rust,no_run
+/// # use serenity::http;
+/// #
+/// # let webhook = http::get_webhook_with_token(0, "").unwrap();
+/// #
+/// let _ = webhook.execute(false, |mut w| {
+///     w.content("<@1234> take a look at this");
+///     w.allowed_mentions(|mut am| {
+///         am.empty_parse();
+///         am.users(vec![1234]);
+///
+///         am
+///     });
+///
+///     w
+/// });
+/// ```
+///
+/// [`ExecuteWebhook::allowed_mentions`]: struct.ExecuteWebhook.html#method.allowed_mentions
+#[derive(Clone, Debug)]
+pub struct CreateAllowedMentions {
+    parse: Vec<&'static str>,
+    users: Vec<u64>,
+    roles: Vec<u64>,
+    replied_user: Option<bool>,
+}
+
+impl CreateAllowedMentions {
+    /// Whether to allow `@everyone`/`@here` mentions to ping.
+    pub fn everyone(&mut self, allow: bool) {
+        self.toggle_parse("everyone", allow);
+    }
+
+    /// Whether to allow role mentions to ping, unless overridden by an
+    /// explicit list given via [`roles`].
+    ///
+    /// [`roles`]: #method.roles
+    pub fn parse_roles(&mut self, allow: bool) {
+        self.toggle_parse("roles", allow);
+    }
+
+    /// Whether to allow user mentions to ping, unless overridden by an
+    /// explicit list given via [`users`].
+    ///
+    /// [`users`]: #method.users
+    pub fn parse_users(&mut self, allow: bool) {
+        self.toggle_parse("users", allow);
+    }
+
+    /// Clears the default parse list, suppressing every mention category
+    /// unless it is explicitly whitelisted via [`users`] or [`roles`].
+    ///
+    /// [`users`]: #method.users
+    /// [`roles`]: #method.roles
+    pub fn empty_parse(&mut self) {
+        self.parse.clear();
+    }
+
+    /// Restores the default parse list, allowing every mention category to
+    /// ping.
+    pub fn all(&mut self) {
+        self.parse = default_parse();
+    }
+
+    /// Whitelists specific users to ping, regardless of [`parse_users`].
+    ///
+    /// Discord rejects `"users"` being present in `parse` alongside an
+    /// explicit `users` list, so setting this removes `"users"` from the
+    /// parse list.
+    ///
+    /// [`parse_users`]: #method.parse_users
+    pub fn users(&mut self, users: Vec<u64>) {
+        self.users = users;
+        self.parse.retain(|&kind| kind != "users");
+    }
+
+    /// Whitelists specific roles to ping, regardless of [`parse_roles`].
+    ///
+    /// [`parse_roles`]: #method.parse_roles
+    pub fn roles(&mut self, roles: Vec<u64>) {
+        self.roles = roles;
+        self.parse.retain(|&kind| kind != "roles");
+    }
+
+    /// Whether to mention the user being replied to, if this execution is a
+    /// reply.
+    pub fn replied_user(&mut self, mention: bool) {
+        self.replied_user = Some(mention);
+    }
+
+    fn toggle_parse(&mut self, kind: &'static str, allow: bool) {
+        if allow {
+            if !self.parse.contains(&kind) {
+                self.parse.push(kind);
+            }
+        } else {
+            self.parse.retain(|&x| x != kind);
+        }
+    }
+
+    pub(crate) fn build(&self) -> Value {
+        let mut map = Map::new();
+
+        // Discord rejects a category being present in `parse` alongside an
+        // explicit list for that same category, so drop it here regardless
+        // of how `parse` and the explicit lists were reached.
+        let parse: Vec<_> = self.parse.iter()
+            .filter(|&&kind| {
+                (kind != "users" || self.users.is_empty()) && (kind != "roles" || self.roles.is_empty())
+            })
+            .collect();
+
+        map.insert(
+            "parse".to_string(),
+            Value::Array(parse.iter().map(|kind| Value::String(kind.to_string())).collect()),
+        );
+
+        if !self.roles.is_empty() {
+            map.insert(
+                "roles".to_string(),
+                Value::Array(self.roles.iter().map(|id| Value::String(id.to_string())).collect()),
+            );
+        }
+
+        if !self.users.is_empty() {
+            map.insert(
+                "users".to_string(),
+                Value::Array(self.users.iter().map(|id| Value::String(id.to_string())).collect()),
+            );
+        }
+
+        if let Some(replied_user) = self.replied_user {
+            map.insert("replied_user".to_string(), Value::Bool(replied_user));
+        }
+
+        Value::Object(map)
+    }
+}
+
+fn default_parse() -> Vec<&'static str> {
+    vec!["roles", "users", "everyone"]
+}
+
+impl Default for CreateAllowedMentions {
+    /// Returns a default set of values for allowed mentions, where every
+    /// mention category is allowed to ping, matching Discord's behaviour
+    /// when `allowed_mentions` is omitted entirely.
+    fn default() -> CreateAllowedMentions {
+        CreateAllowedMentions {
+            parse: default_parse(),
+            users: Vec::new(),
+            roles: Vec::new(),
+            replied_user: None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn default_parses_every_category_with_no_explicit_lists() {
+        let built = CreateAllowedMentions::default().build();
+
+        assert_eq!(built["roles"], Value::Null);
+        assert_eq!(built["users"], Value::Null);
+
+        let parse = built["parse"].as_array().unwrap();
+        assert!(parse.contains(&Value::String("roles".to_string())));
+        assert!(parse.contains(&Value::String("users".to_string())));
+        assert!(parse.contains(&Value::String("everyone".to_string())));
+    }
+
+    #[test]
+    fn empty_parse_then_users_keeps_users_out_of_parse() {
+        let mut am = CreateAllowedMentions::default();
+        am.empty_parse();
+        am.users(vec![1234]);
+
+        let built = am.build();
+        assert_eq!(built["parse"], Value::Array(Vec::new()));
+        assert_eq!(
+            built["users"],
+            Value::Array(vec![Value::String("1234".to_string())])
+        );
+    }
+
+    #[test]
+    fn users_then_all_still_keeps_users_out_of_parse() {
+        let mut am = CreateAllowedMentions::default();
+        am.users(vec![1234]);
+        am.all();
+
+        let built = am.build();
+        let parse = built["parse"].as_array().unwrap();
+        assert!(!parse.contains(&Value::String("users".to_string())));
+        assert!(parse.contains(&Value::String("roles".to_string())));
+        assert!(parse.contains(&Value::String("everyone".to_string())));
+        assert_eq!(
+            built["users"],
+            Value::Array(vec![Value::String("1234".to_string())])
+        );
+    }
+
+    #[test]
+    fn users_then_parse_users_true_still_keeps_users_out_of_parse() {
+        let mut am = CreateAllowedMentions::default();
+        am.users(vec![1234]);
+        am.parse_users(true);
+
+        let built = am.build();
+        let parse = built["parse"].as_array().unwrap();
+        assert!(!parse.contains(&Value::String("users".to_string())));
+    }
+}
@@ -1,5 +1,11 @@
+use base64;
+use builder::CreateAllowedMentions;
+use http::AttachmentType;
 use serde_json::Value;
 use std::default::Default;
+use std::fs::File;
+use std::io::{self, Read};
+use std::path::Path;
 use utils::VecMap;
 
 /// A builder to create the inner content of a [`Webhook`]'s execution.
@@ -56,7 +62,7 @@ use utils::VecMap;
 /// [`Webhook::execute`]: ../model/webhook/struct.Webhook.html#method.execute
 /// [`execute_webhook`]: ../http/fn.execute_webhook.html
 #[derive(Clone, Debug)]
-pub struct ExecuteWebhook(pub VecMap<&'static str, Value>);
+pub struct ExecuteWebhook(pub VecMap<&'static str, Value>, pub(crate) Vec<AttachmentType>);
 
 impl ExecuteWebhook {
     /// Override the default avatar of the webhook with an image URL.
@@ -83,6 +89,133 @@ impl ExecuteWebhook {
         self.0.insert("avatar_url", Value::String(avatar_url.to_string()));
     }
 
+    /// Override the default avatar of the webhook with raw image bytes,
+    /// rather than a remote URL.
+    ///
+    /// The image's MIME type is detected from its magic bytes (PNG, JPEG,
+    /// GIF, or WEBP), base64-encoded, and stored as a data URI in the same
+    /// field used by [`avatar_url`]. Returns an error if `avatar`'s magic
+    /// bytes don't match one of those formats, rather than guessing.
+    ///
+    /// # Examples
+    ///
+    /// Overriding the default avatar with a bundled image:
+    ///
+    /// ```rust,no_run
+    /// # use serenity::http;
+    /// #
+    /// # let webhook = http::get_webhook_with_token(0, "").unwrap();
+    /// #
+    /// let image = std::fs::read("avatar.png").expect("readable avatar file");
+    ///
+    /// let _ = webhook.execute(false, |mut w| {
+    ///     w.avatar_bytes(&image).expect("recognized image format");
+    ///     w.content("Here's a webhook");
+    ///
+    ///     w
+    /// });
+    /// ```
+    ///
+    /// [`avatar_url`]: #method.avatar_url
+    pub fn avatar_bytes(&mut self, avatar: &[u8]) -> io::Result<()> {
+        self.0.insert("avatar_url", Value::String(encode_image(avatar)?));
+
+        Ok(())
+    }
+
+    /// Override the default avatar of the webhook with a local image file,
+    /// rather than a remote URL.
+    ///
+    /// This is a convenience wrapper around [`avatar_bytes`] that reads the
+    /// file at `path` first.
+    ///
+    /// # Examples
+    ///
+    /// Overriding the default avatar with a file on disk:
+    ///
+    /// ```rust,no_run
+    /// # use serenity::http;
+    /// #
+    /// # let webhook = http::get_webhook_with_token(0, "").unwrap();
+    /// #
+    /// let _ = webhook.execute(false, |mut w| {
+    ///     w.avatar("avatar.png").expect("valid avatar file");
+    ///     w.content("Here's a webhook");
+    ///
+    ///     w
+    /// });
+    /// ```
+    ///
+    /// [`avatar_bytes`]: #method.avatar_bytes
+    pub fn avatar<P: AsRef<Path>>(&mut self, path: P) -> io::Result<()> {
+        let mut file = File::open(path)?;
+        let mut data = Vec::new();
+        file.read_to_end(&mut data)?;
+
+        self.avatar_bytes(&data)
+    }
+
+    /// Add a file to be attached to the message, switching the execution
+    /// request from a plain JSON body to a `multipart/form-data` request.
+    ///
+    /// May be called multiple times to attach several files; each is sent
+    /// as its own `file0`, `file1`, ... part alongside the JSON payload.
+    ///
+    /// # Examples
+    ///
+    /// Attaching a local image file:
+    ///
+    /// ```rust,no_run
+    /// # use serenity::http;
+    /// # use serenity::http::AttachmentType;
+    /// # use std::path::PathBuf;
+    /// #
+    /// # let webhook = http::get_webhook_with_token(0, "").unwrap();
+    /// #
+    /// let _ = webhook.execute(false, |mut w| {
+    ///     w.content("Look at this:");
+    ///     w.attachment(AttachmentType::Path(PathBuf::from("cat.png")));
+    ///
+    ///     w
+    /// });
+    /// ```
+    pub fn attachment(&mut self, attachment: AttachmentType) {
+        self.1.push(attachment);
+    }
+
+    /// Set the allowed mentions for the message, controlling which roles,
+    /// users, and `@everyone`/`@here` pings actually notify someone.
+    ///
+    /// By default, i.e. without calling this method, all mentions resolve
+    /// and ping as normal.
+    ///
+    /// # Examples
+    ///
+    /// Suppressing all pings from a webhook that echoes untrusted content:
+    ///
+    /// ```rust,no_run
+    /// # use serenity::http;
+    /// #
+    /// # let webhook = http::get_webhook_with_token(0, "").unwrap();
+    /// #
+    /// let _ = webhook.execute(false, |mut w| {
+    ///     w.content("This won't ping @everyone or anyone else.");
+    ///     w.allowed_mentions(|mut am| {
+    ///         am.empty_parse();
+    ///
+    ///         am
+    ///     });
+    ///
+    ///     w
+    /// });
+    /// ```
+    pub fn allowed_mentions<F>(&mut self, f: F)
+        where F: FnOnce(CreateAllowedMentions) -> CreateAllowedMentions {
+        let allowed_mentions = f(CreateAllowedMentions::default());
+
+        self.0.insert("allowed_mentions", allowed_mentions.build());
+    }
+
     /// Set the content of the message.
     ///
     /// Note that when setting at least one embed via [`embeds`], this may be
@@ -210,6 +343,81 @@ impl Default for ExecuteWebhook {
         let mut map = VecMap::new();
         map.insert("tts", Value::Bool(false));
 
-        ExecuteWebhook(map)
+        ExecuteWebhook(map, Vec::new())
+    }
+}
+
+/// Detects an image's MIME type from its magic bytes and returns it
+/// base64-encoded as a `data:image/<type>;base64,<data>` URI, as accepted by
+/// Discord's avatar fields.
+///
+/// Returns an [`io::ErrorKind::InvalidData`] error if `bytes` don't match the
+/// magic bytes of a PNG, JPEG, GIF, or WEBP image.
+fn encode_image(bytes: &[u8]) -> io::Result<String> {
+    let kind = image_kind(bytes).ok_or_else(|| io::Error::new(
+        io::ErrorKind::InvalidData,
+        "unrecognized image format; expected PNG, JPEG, GIF, or WEBP",
+    ))?;
+
+    Ok(format!("data:image/{};base64,{}", kind, base64::encode(bytes)))
+}
+
+/// Returns the image format name matching `bytes`' magic bytes, or `None` if
+/// none of the recognized formats (PNG, JPEG, GIF, WEBP) match.
+fn image_kind(bytes: &[u8]) -> Option<&'static str> {
+    if bytes.starts_with(b"\x89PNG\r\n\x1a\n") {
+        Some("png")
+    } else if bytes.starts_with(b"\xff\xd8\xff") {
+        Some("jpeg")
+    } else if bytes.starts_with(b"GIF87a") || bytes.starts_with(b"GIF89a") {
+        Some("gif")
+    } else if bytes.len() >= 12 && &bytes[0..4] == b"RIFF" && &bytes[8..12] == b"WEBP" {
+        Some("webp")
+    } else {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn image_kind_detects_png() {
+        assert_eq!(image_kind(b"\x89PNG\r\n\x1a\nrest-of-file"), Some("png"));
+    }
+
+    #[test]
+    fn image_kind_detects_jpeg() {
+        assert_eq!(image_kind(b"\xff\xd8\xffrest-of-file"), Some("jpeg"));
+    }
+
+    #[test]
+    fn image_kind_detects_gif() {
+        assert_eq!(image_kind(b"GIF87arest-of-file"), Some("gif"));
+        assert_eq!(image_kind(b"GIF89arest-of-file"), Some("gif"));
+    }
+
+    #[test]
+    fn image_kind_detects_webp() {
+        assert_eq!(image_kind(b"RIFF\0\0\0\0WEBPrest-of-file"), Some("webp"));
+    }
+
+    #[test]
+    fn image_kind_rejects_unrecognized_formats() {
+        assert_eq!(image_kind(b"BM unrecognized bitmap data"), None);
+        assert_eq!(image_kind(b""), None);
+    }
+
+    #[test]
+    fn encode_image_errors_on_unrecognized_format() {
+        let err = encode_image(b"not a real image").unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+    }
+
+    #[test]
+    fn encode_image_encodes_a_recognized_format() {
+        let data_uri = encode_image(b"\x89PNG\r\n\x1a\nrest-of-file").unwrap();
+        assert!(data_uri.starts_with("data:image/png;base64,"));
     }
 }
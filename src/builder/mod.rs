@@ -0,0 +1,5 @@
+mod create_allowed_mentions;
+mod execute_webhook;
+
+pub use self::create_allowed_mentions::CreateAllowedMentions;
+pub use self::execute_webhook::ExecuteWebhook;
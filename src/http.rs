@@ -0,0 +1,124 @@
+use builder::ExecuteWebhook;
+use internal::prelude::*;
+use model::channel::Message;
+use reqwest::multipart::{Form, Part};
+use reqwest::{Client, Response};
+use serde_json;
+use std::fs::File;
+use std::io::Read;
+use std::path::PathBuf;
+
+const API_URL: &str = "https://discord.com/api/v6";
+
+lazy_static! {
+    static ref HTTP_CLIENT: Client = Client::new();
+}
+
+/// The type of data being attached to a webhook execution, used with
+/// [`ExecuteWebhook::attachment`].
+///
+/// Sending any attachment switches the underlying webhook request from a
+/// plain JSON body to a `multipart/form-data` request, with the JSON payload
+/// carried in the `payload_json` field and each attachment added as its own
+/// `file0`, `file1`, ... part.
+///
+/// [`ExecuteWebhook::attachment`]: ../builder/struct.ExecuteWebhook.html#method.attachment
+#[derive(Clone, Debug)]
+pub enum AttachmentType {
+    /// Raw file bytes, sent under the given filename.
+    Bytes { data: Vec<u8>, filename: String },
+    /// A file on disk. The filename sent to Discord is taken from the path's
+    /// file name.
+    Path(PathBuf),
+    /// A remotely-hosted file, fetched by URL before being attached.
+    Url(String),
+}
+
+/// Executes a webhook, delivering its built payload to Discord.
+///
+/// `wait` drives the `?wait=` query parameter on the route. When `true`,
+/// Discord waits for the message to be created and responds with the full
+/// message object instead of an empty `204`, which is returned here as
+/// `Some(message)`; when `false`, this always returns `None`.
+///
+/// When `execution` carries one or more attachments, the request is sent as
+/// `multipart/form-data`, with the JSON payload placed in the `payload_json`
+/// field and each attachment sent as its own `file0`, `file1`, ... part.
+/// Otherwise it is sent as a plain JSON body, as before.
+pub fn execute_webhook(
+    webhook_id: u64,
+    token: &str,
+    wait: bool,
+    execution: &ExecuteWebhook,
+) -> Result<Option<Message>> {
+    let route = format!("{}/webhooks/{}/{}?wait={}", API_URL, webhook_id, token, wait);
+
+    let response = if execution.1.is_empty() {
+        request_json(&route, &execution.0)?
+    } else {
+        request_multipart(&route, &execution.0, &execution.1)?
+    }.error_for_status()?;
+
+    if wait {
+        Ok(Some(serde_json::from_reader(response)?))
+    } else {
+        Ok(None)
+    }
+}
+
+/// Sends `map` as a plain JSON body, used when an execution carries no
+/// attachments.
+fn request_json(route: &str, map: &Value) -> Result<Response> {
+    let response = HTTP_CLIENT.post(route).json(map).send()?;
+
+    Ok(response)
+}
+
+/// Sends `map` and `attachments` as a `multipart/form-data` body: the JSON
+/// payload is carried in the `payload_json` field, and each attachment is
+/// resolved to its raw bytes and added as its own `file0`, `file1`, ... part.
+fn request_multipart(route: &str, map: &Value, attachments: &[AttachmentType]) -> Result<Response> {
+    let mut form = Form::new().text("payload_json", serde_json::to_string(map)?);
+
+    for (index, attachment) in attachments.iter().enumerate() {
+        let (data, filename) = resolve_attachment(attachment)?;
+
+        form = form.part(format!("file{}", index), Part::bytes(data).file_name(filename));
+    }
+
+    let response = HTTP_CLIENT.post(route).multipart(form).send()?;
+
+    Ok(response)
+}
+
+/// Resolves an [`AttachmentType`] to its raw bytes and a filename, reading
+/// from disk or fetching over HTTP as needed.
+fn resolve_attachment(attachment: &AttachmentType) -> Result<(Vec<u8>, String)> {
+    match *attachment {
+        AttachmentType::Bytes { ref data, ref filename } => Ok((data.clone(), filename.clone())),
+        AttachmentType::Path(ref path) => {
+            let mut file = File::open(path)?;
+            let mut data = Vec::new();
+            file.read_to_end(&mut data)?;
+
+            let filename = path.file_name()
+                .map(|name| name.to_string_lossy().into_owned())
+                .unwrap_or_else(|| "file".to_string());
+
+            Ok((data, filename))
+        },
+        AttachmentType::Url(ref url) => {
+            let mut response = HTTP_CLIENT.get(url).send()?;
+            let mut data = Vec::new();
+            response.read_to_end(&mut data)?;
+
+            let path = url.split(&['?', '#'][..]).next().unwrap_or(url);
+            let filename = match path.rsplit('/').next() {
+                Some(segment) if !segment.is_empty() => segment,
+                _ => "file",
+            }.to_string();
+
+            Ok((data, filename))
+        },
+    }
+}
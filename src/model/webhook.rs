@@ -0,0 +1,62 @@
+use builder::ExecuteWebhook;
+use http;
+use internal::prelude::*;
+use model::channel::Message;
+
+/// A representation of a webhook, which is a low-effort way to post
+/// messages to channels. They do not necessarily require a bot user or
+/// authentication to use.
+#[derive(Clone, Debug)]
+pub struct Webhook {
+    /// The unique Id.
+    pub id: u64,
+    /// The default avatar.
+    pub avatar: Option<String>,
+    /// The Id of the channel that owns the webhook.
+    pub channel_id: u64,
+    /// The Id of the guild that owns the webhook.
+    pub guild_id: Option<u64>,
+    /// The default name of the webhook.
+    pub name: Option<String>,
+    /// The webhook's secure token.
+    pub token: String,
+}
+
+impl Webhook {
+    /// Executes a webhook with the given content.
+    ///
+    /// Pass `true` for `wait` to have Discord wait for the message to be
+    /// created before responding; this makes the call return the created
+    /// [`Message`]. Passing `false` returns `None`, matching the previous
+    /// behaviour, which is useful when the message doesn't need to be
+    /// edited or deleted afterwards.
+    ///
+    /// # Examples
+    ///
+    /// Execute a webhook with message content of `test` and capture the
+    /// created message's Id:
+    ///
+    /// ```rust,no_run
+    /// # use serenity::http;
+    /// #
+    /// # let webhook = http::get_webhook_with_token(0, "").unwrap();
+    /// #
+    /// let message = webhook.execute(true, |mut w| {
+    ///     w.content("test");
+    ///
+    ///     w
+    /// }).expect("Error executing");
+    ///
+    /// if let Some(message) = message {
+    ///     println!("Created message with Id {}", message.id);
+    /// }
+    /// ```
+    ///
+    /// [`Message`]: channel/struct.Message.html
+    pub fn execute<F>(&self, wait: bool, f: F) -> Result<Option<Message>>
+        where F: FnOnce(ExecuteWebhook) -> ExecuteWebhook {
+        let execution = f(ExecuteWebhook::default());
+
+        http::execute_webhook(self.id, &self.token, wait, &execution)
+    }
+}